@@ -15,6 +15,10 @@ export default {
         title: 'Справочник API',
         type: 'page'
     },
+    cli: {
+        title: 'Справочник CLI',
+        type: 'page'
+    },
     ci: {
         title: 'Результаты CI',
         type: 'page'
@@ -3,7 +3,7 @@
 //! Этот крейт предоставляет команды автоматизации сборки для воркспейса.
 //!
 //! См. [`HELP_TEXT`] для полного списка доступных команд и информации по использованию.
-use std::fs;
+use std::{collections::BTreeMap, env::consts::EXE_EXTENSION, fs};
 
 use anyhow::{Context, Result, bail};
 use serde::Deserialize;
@@ -27,9 +27,11 @@ pub const HELP_TEXT: &str = r#"xtask
   test-unit    Запустить только unit-тесты (без e2e)
   test-e2e     Запустить только e2e-тесты (собирает бинарники)
   ci           Запустить fmt-check + clippy + test (профиль CI)
+  check-matrix Проверить сборку во всех конфигурациях фич и на MSRV
   docs         Собрать документацию (rustdoc JSON + Nextra)
   docs-dev     Запустить dev сервер Nextra
   docs-rustdoc Сгенерировать API документацию из rustdoc JSON
+  docs-cli     Сгенерировать справку CLI из --help бинарников
 
 Примечание:
   cargo-nextest устанавливается автоматически при первом запуске тестов
@@ -62,9 +64,11 @@ fn main() -> Result<()> {
             test_doc(&sh)?;
             Ok(())
         }
+        "check-matrix" => check_matrix(),
         "docs" => docs_build(),
         "docs-dev" => docs_dev(),
         "docs-rustdoc" => docs_rustdoc(),
+        "docs-cli" => docs_cli(),
         other => bail!("Неизвестная команда: {other}\n\nЗапустите: cargo run -p xtask -- help"),
     }
 }
@@ -116,14 +120,91 @@ fn test_doc(sh: &Shell) -> Result<()> {
     Ok(())
 }
 
+/// Проверить сборку крейтов во всех конфигурациях фич и на MSRV.
+///
+/// Для каждого крейта воркспейса запускается `cargo check` с фичами по умолчанию,
+/// а при наличии фич — ещё с `--no-default-features`, с каждой фичей по отдельности
+/// и с `--all-features` (аналог `cargo hack --each-feature`). Если в манифесте задан
+/// `rust-version`, крейт дополнительно проверяется на этом тулчейне.
+///
+/// В конце печатает таблицу результатов и завершается с ошибкой, если хотя бы
+/// одна конфигурация не собралась.
+fn check_matrix() -> Result<()> {
+    let sh = Shell::new()?;
+    let metadata = cargo_metadata(&sh)?;
+    let mut rows = Vec::new();
+
+    for package in &metadata.packages {
+        let name = &package.name;
+
+        for flags in feature_configs(package) {
+            let label = if flags.is_empty() { "default".to_string() } else { flags.join(" ") };
+            eprintln!("Проверка {name} [{label}]...");
+            let result = cmd!(sh, "cargo check -p {name} {flags...}").ignore_status().output()?;
+            rows.push((name.as_str(), label, CiCheckResult::from(result)));
+        }
+
+        match &package.rust_version {
+            Some(msrv) => {
+                let toolchain = format!("+{msrv}");
+                eprintln!("Проверка {name} [MSRV {msrv}]...");
+                let result =
+                    cmd!(sh, "cargo {toolchain} check -p {name}").ignore_status().output()?;
+                rows.push((name.as_str(), format!("MSRV {msrv}"), CiCheckResult::from(result)));
+            }
+            None => eprintln!("  {name}: rust-version не задан, проверка MSRV пропущена"),
+        }
+    }
+
+    println!("| Крейт | Конфигурация | Статус |");
+    println!("|-------|--------------|--------|");
+    for (name, label, result) in &rows {
+        let emoji = if result.success { "✅" } else { "❌" };
+        println!("| {name} | `{label}` | {emoji} |");
+    }
+
+    let failed: Vec<_> = rows.iter().filter(|(_, _, result)| !result.success).collect();
+    for (name, label, result) in &failed {
+        eprintln!("\n--- {name} [{label}] ---\n{}", result.stderr);
+    }
+    if !failed.is_empty() {
+        bail!("Матрица сборки: {} из {} конфигураций с ошибками", failed.len(), rows.len());
+    }
+
+    Ok(())
+}
+
+/// Получить наборы флагов фич для проверки крейта.
+///
+/// Первый набор всегда пустой (фичи по умолчанию).
+fn feature_configs(package: &Package) -> Vec<Vec<String>> {
+    let mut configs = vec![Vec::new()];
+    if package.features.is_empty() {
+        return configs;
+    }
+
+    configs.push(vec!["--no-default-features".to_string()]);
+    for feature in package.features.keys().filter(|f| *f != "default") {
+        configs.push(vec![
+            "--no-default-features".to_string(),
+            "--features".to_string(),
+            feature.clone(),
+        ]);
+    }
+    configs.push(vec!["--all-features".to_string()]);
+
+    configs
+}
+
 /// Собрать полную документацию.
 ///
 /// Эта команда выполняет следующие шаги:
 /// 1. Запускает тесты и сохраняет результаты в документации
 /// 2. Генерирует rustdoc JSON для всех крейтов воркспейса
 /// 3. Конвертирует JSON в Markdown через rustdoc-md
-/// 4. Устанавливает зависимости Nextra
-/// 5. Собирает статический сайт документации
+/// 4. Генерирует справку CLI из `--help` бинарников
+/// 5. Устанавливает зависимости Nextra
+/// 6. Собирает статический сайт документации
 ///
 /// Итоговая документация будет доступна в `docs/out/`.
 fn docs_build() -> Result<()> {
@@ -136,6 +217,9 @@ fn docs_build() -> Result<()> {
     // Генерация rustdoc JSON -> Markdown
     docs_rustdoc()?;
 
+    // Генерация справки CLI
+    docs_cli()?;
+
     // Установка зависимостей
     sh.change_dir(&docs_dir);
     cmd!(sh, "bun install").run()?;
@@ -230,6 +314,107 @@ fn docs_rustdoc() -> Result<()> {
     Ok(())
 }
 
+/// Сгенерировать справку CLI из `--help` бинарников воркспейса.
+///
+/// Эта команда:
+/// 1. Собирает все бинарники воркспейса (кроме `xtask`)
+/// 2. Запускает каждый бинарник с `--version` и `--help`
+/// 3. Для каждой подкоманды из секции `Commands:` запускает `<бинарник> <подкоманда> --help`
+/// 4. Записывает Markdown-страницы и индексную страницу
+///
+/// Сгенерированная справка будет размещена в `docs/content/cli/`.
+fn docs_cli() -> Result<()> {
+    let sh = Shell::new()?;
+    let project = project_root()?;
+    let cli_dir = project.join("docs/content/cli");
+
+    // Создание директории cli_dir
+    fs::create_dir_all(&cli_dir)?;
+
+    let binaries = workspace_binaries(&sh)?;
+    if binaries.is_empty() {
+        eprintln!("Пропуск справки CLI: бинарники не найдены в воркспейсе");
+        return Ok(());
+    }
+    eprintln!("Найдены бинарники: {}", binaries.join(", "));
+
+    eprintln!("Сборка бинарников...");
+    cmd!(sh, "cargo build --workspace --bins").run()?;
+
+    let timestamp = now_iso();
+    let mut pages = Vec::with_capacity(binaries.len());
+
+    for binary in &binaries {
+        eprintln!("Генерация справки для {binary}...");
+
+        let mut path = project.join("target/debug").join(binary);
+        if !EXE_EXTENSION.is_empty() {
+            path.set_extension(EXE_EXTENSION);
+        }
+
+        let version = cmd!(sh, "{path} --version").read()?;
+        let help = cmd!(sh, "{path} --help").read()?;
+
+        let mut content = String::new();
+        content.push_str(&format!("# {binary}\n\n"));
+        content.push_str(&format!("> **Версия:** {version}\n"));
+        content.push_str(&format!("> **Дата:** {timestamp}\n\n"));
+        content.push_str(&format!("## Использование\n\n```text\n{help}\n```\n"));
+
+        for subcommand in parse_subcommands(&help) {
+            let sub_help = cmd!(sh, "{path} {subcommand} --help").read()?;
+            content.push_str(&format!("\n## {binary} {subcommand}\n\n```text\n{sub_help}\n```\n"));
+        }
+
+        let output_path = cli_dir.join(format!("{binary}.md"));
+        fs::write(&output_path, content)?;
+        eprintln!("  -> {binary}.md сгенерирован");
+
+        pages.push((binary.as_str(), version));
+    }
+
+    write_cli_index(&cli_dir, &timestamp, &pages)?;
+
+    eprintln!("Справка CLI сгенерирована в docs/content/cli/");
+    Ok(())
+}
+
+/// Извлечь имена подкоманд из секции `Commands:` вывода clap `--help`.
+///
+/// Встроенная подкоманда `help` пропускается.
+fn parse_subcommands(help: &str) -> Vec<&str> {
+    help.lines()
+        .skip_while(|line| line.trim() != "Commands:")
+        .skip(1)
+        .take_while(|line| !line.trim().is_empty())
+        .filter_map(|line| line.split_whitespace().next())
+        .filter(|name| *name != "help")
+        .collect()
+}
+
+/// Записать индексную страницу справки CLI.
+fn write_cli_index(
+    cli_dir: &std::path::Path,
+    timestamp: &str,
+    pages: &[(&str, String)],
+) -> Result<()> {
+    let mut content = String::new();
+    content.push_str("# Справочник CLI\n\n");
+    content.push_str(&format!("> **Дата:** {timestamp}\n\n"));
+
+    content.push_str("| Бинарник | Версия |\n");
+    content.push_str("|----------|--------|\n");
+
+    for (binary, version) in pages {
+        content.push_str(&format!("| [{binary}](./cli/{binary}) | `{version}` |\n"));
+    }
+
+    let path = cli_dir.join("index.md");
+    fs::write(&path, content)?;
+
+    Ok(())
+}
+
 /// Запустить CI проверки и сохранить результаты в документации.
 ///
 /// Эта функция создаёт папку `docs/content/ci/` и сохраняет результаты:
@@ -448,18 +633,48 @@ struct CargoMetadata {
 #[derive(Deserialize)]
 struct Package {
     name: String,
+    targets: Vec<Target>,
+    features: BTreeMap<String, Vec<String>>,
+    rust_version: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Target {
+    name: String,
+    kind: Vec<String>,
+}
+
+/// Получить метаданные воркспейса через `cargo metadata`.
+fn cargo_metadata(sh: &Shell) -> Result<CargoMetadata> {
+    let output = cmd!(sh, "cargo metadata --no-deps --format-version 1").read()?;
+    serde_json::from_str(&output).context("не удалось распарсить cargo metadata")
 }
 
 /// Получить список крейтов воркспейса.
 fn workspace_crates(sh: &Shell) -> Result<Vec<String>> {
-    let output = cmd!(sh, "cargo metadata --no-deps --format-version 1").read()?;
-    let metadata: CargoMetadata =
-        serde_json::from_str(&output).context("не удалось распарсить cargo metadata")?;
+    let metadata = cargo_metadata(sh)?;
 
     let crates: Vec<String> = metadata.packages.into_iter().map(|p| p.name).collect();
     Ok(crates)
 }
 
+/// Получить список бинарников воркспейса.
+///
+/// Бинарник самого `xtask` исключается: это не пользовательский CLI.
+fn workspace_binaries(sh: &Shell) -> Result<Vec<String>> {
+    let metadata = cargo_metadata(sh)?;
+
+    let binaries: Vec<String> = metadata
+        .packages
+        .into_iter()
+        .filter(|p| p.name != "xtask")
+        .flat_map(|p| p.targets)
+        .filter(|t| t.kind.iter().any(|kind| kind == "bin"))
+        .map(|t| t.name)
+        .collect();
+    Ok(binaries)
+}
+
 /// Получить корневую директорию проекта.
 ///
 /// Эта функция определяет корень проекта, находя родительскую директорию